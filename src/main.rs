@@ -1,5 +1,8 @@
 use std::io::Read;
+use std::time::{Duration, Instant};
 
+use regex::Regex;
+use unicode_width::UnicodeWidthChar;
 use shuten_core::{
     event::{Event, Key, MouseEvent},
     geom::{lerp, Pos2, Rect},
@@ -20,25 +23,59 @@ fn main() -> std::io::Result<()> {
 
     let mut terminal = Terminal::new(Config::default())?;
     let mut app = App::new(lines.lines());
+    app.area = terminal.rect();
+    // park at the top once the real width (and thus the visual-row count) is known
+    app.pos = app.wrap(app.area.width() as usize).len();
 
     // initial paint
     terminal.paint(|mut canvas| app.draw_ui(&mut canvas))?;
 
     while let Ok(event) = terminal.wait_for_next_event() {
+        app.area = terminal.rect();
         match event {
             Event::Mouse(ev, _) => {
-                if let MouseEvent::Scroll { dir, .. } = ev {
-                    if dir.y.is_negative() {
-                        app.scroll_down(3)
-                    } else {
-                        app.scroll_up(3)
+                match ev {
+                    MouseEvent::Scroll { dir, .. } => {
+                        if dir.y.is_negative() {
+                            app.scroll_down(3)
+                        } else {
+                            app.scroll_up(3)
+                        }
                     }
+                    MouseEvent::Down { pos, .. } => app.begin_selection(pos),
+                    MouseEvent::Drag { pos, .. } => app.drag_selection(pos),
+                    _ => {}
                 }
                 app.cursor = ev.pos()
             }
 
+            // while a query is being typed every keystroke feeds the search
+            Event::Keyboard(key, ..) if matches!(app.mode, Mode::Search(_)) => match key {
+                Key::Char(c) => app.push_query(c),
+                Key::Backspace => app.pop_query(),
+                Key::Enter => app.commit_query(),
+                Key::Escape => app.cancel_query(),
+                _ => continue,
+            },
+
+            Event::Keyboard(Key::Char('d'), m, ..) if m.is_ctrl() => {
+                app.scroll_up(app.half_page())
+            }
+            Event::Keyboard(Key::Char('u'), m, ..) if m.is_ctrl() => {
+                app.scroll_down(app.half_page())
+            }
+
+            Event::Keyboard(Key::Char('/'), ..) => app.begin_query(),
+            Event::Keyboard(Key::Char('n'), ..) => app.next_match(),
+            Event::Keyboard(Key::Char('N'), ..) => app.prev_match(),
+
+            Event::Keyboard(Key::Char('y'), ..) => app.copy_selection(),
+
             Event::Keyboard(Key::Char(' '), ..) => app.enabled = !app.enabled,
 
+            Event::Keyboard(Key::Char('p'), ..) => app.cycle_profile(),
+            Event::Keyboard(Key::Char('f'), ..) => app.cycle_falloff(),
+
             Event::Keyboard(Key::PageUp, ..) => {
                 app.scroll_down(terminal.rect().height() as usize * 2)
             }
@@ -50,6 +87,13 @@ fn main() -> std::io::Result<()> {
             Event::Keyboard(Key::Up, ..) => app.scroll_down(1),
             Event::Keyboard(Key::Down, ..) => app.scroll_up(1),
 
+            // vi-style motions (j/k, g/G) and numeric counts
+            Event::Keyboard(key, ..) => {
+                if !app.dispatch(key) {
+                    continue;
+                }
+            }
+
             Event::Quit => break,
             _ => continue,
         }
@@ -60,11 +104,66 @@ fn main() -> std::io::Result<()> {
     Ok(())
 }
 
+/// A compiled incremental search over [`App::lines`].
+///
+/// `matches` are stored as `(line_idx, char_start, char_end)` with the columns
+/// in characters (not bytes) so the painter can highlight them directly, and
+/// `current` indexes the match the viewport is currently parked on.
+struct Search {
+    query: String,
+    // reused when the query is unchanged so a re-search skips recompilation
+    re: Regex,
+    matches: Vec<(usize, usize, usize)>,
+    current: usize,
+}
+
+/// An in-flight text selection, stored as `(line, column)` into [`App::lines`]
+/// rather than screen cells so it stays glued to the underlying text while the
+/// viewport scrolls underneath it.
+struct Selection {
+    anchor: (usize, usize),
+    end: (usize, usize),
+}
+
+/// One visual row produced by the wrap pass: the half-open char slice
+/// `start..end` of logical line `line` that fits within the current width.
+struct Row {
+    line: usize,
+    start: usize,
+    end: usize,
+}
+
+/// A source glyph's lead cell as it was placed on screen by the wrap walk,
+/// letting a screen cell be mapped back to its `(line, column)`.
+struct Placed {
+    pos: Pos2,
+    line: usize,
+    col: usize,
+}
+
+/// Which command layer keystrokes are routed through.
+///
+/// `Count` holds the digits of a pending numeric prefix (e.g. `42` before a
+/// `G`), and `Search` holds the query being typed after `/`.
+enum Mode {
+    Normal,
+    Count(String),
+    Search(String),
+}
+
 struct App {
     cursor: Pos2,
     enabled: bool,
     lines: Vec<String>,
     pos: usize,
+    mode: Mode,
+    search: Option<Search>,
+    area: Rect,
+    selection: Option<Selection>,
+    last_click: Option<(Instant, Pos2)>,
+    profile: Profile,
+    falloff: Falloff,
+    radius: f32,
 }
 
 impl App {
@@ -78,6 +177,16 @@ impl App {
             enabled: false,
             pos: lines.len(),
             lines,
+            mode: Mode::Normal,
+            search: None,
+            area: Rect::default(),
+            selection: None,
+            last_click: None,
+            // default to the original torch: a radial fade that reaches full
+            // shadow at roughly the same distance the old `sqrt`-based curve did
+            profile: Profile::Radial,
+            falloff: Falloff::Linear,
+            radius: 16.0,
         }
     }
 
@@ -86,7 +195,514 @@ impl App {
     }
 
     fn scroll_down(&mut self, delta: usize) {
-        self.pos = (self.pos + delta).min(self.lines.len());
+        let total = self.wrap(self.area.width() as usize).len();
+        self.pos = (self.pos + delta).min(total);
+    }
+
+    /// Half a viewport's worth of rows, at least one, for `Ctrl-D`/`Ctrl-U`.
+    fn half_page(&self) -> usize {
+        (self.area.height() as usize / 2).max(1)
+    }
+
+    /// Park the viewport on logical line `line` (1-based, clamped), using the
+    /// same visual-row relation as [`App::scroll_to_current`].
+    fn goto_line(&mut self, line: usize) {
+        let line = line.saturating_sub(1).min(self.lines.len().saturating_sub(1));
+        let rows = self.wrap(self.area.width() as usize);
+        if let Some(row) = rows.iter().position(|r| r.line == line) {
+            self.pos = rows.len().saturating_sub(row);
+        }
+    }
+
+    /// Route a keystroke through the normal/count command layer: digits build a
+    /// pending count, and a motion key consumes it against `self.pos`. Returns
+    /// `false` for keys this layer does not handle so the caller can fall back.
+    fn dispatch(&mut self, key: Key) -> bool {
+        // build up a numeric prefix; a leading zero is not a count
+        if let Key::Char(c @ '0'..='9') = key {
+            if c != '0' || matches!(self.mode, Mode::Count(_)) {
+                match &mut self.mode {
+                    Mode::Count(buf) => buf.push(c),
+                    _ => self.mode = Mode::Count(String::from(c)),
+                }
+                return true;
+            }
+        }
+
+        let count = match &self.mode {
+            Mode::Count(buf) => buf.parse().ok(),
+            _ => None,
+        };
+        self.mode = Mode::Normal;
+
+        match key {
+            Key::Char('j') => self.scroll_up(count.unwrap_or(1)),
+            Key::Char('k') => self.scroll_down(count.unwrap_or(1)),
+            Key::Char('g') => self.goto_line(count.unwrap_or(1)),
+            Key::Char('G') => self.goto_line(count.unwrap_or(self.lines.len())),
+            _ => return false,
+        }
+        true
+    }
+}
+
+impl App {
+    fn begin_query(&mut self) {
+        self.mode = Mode::Search(String::new());
+        self.search = None;
+    }
+
+    fn push_query(&mut self, c: char) {
+        if let Mode::Search(input) = &mut self.mode {
+            input.push(c);
+            self.recompute();
+        }
+    }
+
+    fn pop_query(&mut self) {
+        if let Mode::Search(input) = &mut self.mode {
+            input.pop();
+            self.recompute();
+        }
+    }
+
+    fn commit_query(&mut self) {
+        self.mode = Mode::Normal;
+        self.scroll_to_current();
+    }
+
+    fn cancel_query(&mut self) {
+        self.mode = Mode::Normal;
+        self.search = None;
+    }
+
+    /// Re-derive the match set from the in-progress query. An empty query
+    /// clears the search and a query that does not compile yet leaves no
+    /// highlights rather than panicking.
+    fn recompute(&mut self) {
+        let Mode::Search(query) = &self.mode else {
+            return;
+        };
+        let query = query.clone();
+
+        if query.is_empty() {
+            self.search = None;
+            return;
+        }
+
+        // reuse the compiled regex when the pattern is unchanged, only paying to
+        // recompile when the query actually differs
+        let re = match self.search.take() {
+            Some(search) if search.query == query => search.re,
+            _ => {
+                let Ok(re) = Regex::new(&query) else {
+                    self.search = None;
+                    return;
+                };
+                re
+            }
+        };
+
+        let mut matches = Vec::new();
+        for (idx, line) in self.lines.iter().enumerate() {
+            for m in re.find_iter(line) {
+                let start = line[..m.start()].chars().count();
+                let end = start + line[m.start()..m.end()].chars().count();
+                matches.push((idx, start, end));
+            }
+        }
+
+        self.search = Some(Search {
+            query,
+            re,
+            matches,
+            current: 0,
+        });
+    }
+
+    fn next_match(&mut self) {
+        if let Some(search) = self.search.as_mut() {
+            if !search.matches.is_empty() {
+                search.current = (search.current + 1) % search.matches.len();
+            }
+        }
+        self.scroll_to_current();
+    }
+
+    fn prev_match(&mut self) {
+        if let Some(search) = self.search.as_mut() {
+            if !search.matches.is_empty() {
+                search.current =
+                    (search.current + search.matches.len() - 1) % search.matches.len();
+            }
+        }
+        self.scroll_to_current();
+    }
+
+    /// Park the viewport so the current match's line sits inside the window,
+    /// using the same `offset = rows.len() - pos` relation over visual rows as
+    /// [`App::draw_ui`].
+    fn scroll_to_current(&mut self) {
+        let Some(line) = self
+            .search
+            .as_ref()
+            .and_then(|s| s.matches.get(s.current))
+            .map(|&(line, ..)| line)
+        else {
+            return;
+        };
+
+        let rows = self.wrap(self.area.width() as usize);
+        if let Some(row) = rows.iter().position(|r| r.line == line) {
+            self.pos = rows.len().saturating_sub(row);
+        }
+    }
+
+    /// The highlight level for the glyph at `(line, col)`, if any.
+    fn highlight(&self, line: usize, col: usize) -> Highlight {
+        let Some(search) = self.search.as_ref() else {
+            return Highlight::None;
+        };
+
+        for (idx, &(l, start, end)) in search.matches.iter().enumerate() {
+            if l == line && (start..end).contains(&col) {
+                return if idx == search.current {
+                    Highlight::Current
+                } else {
+                    Highlight::Match
+                };
+            }
+        }
+
+        Highlight::None
+    }
+}
+
+/// How strongly a cell participates in the current search.
+#[derive(Copy, Clone, PartialEq)]
+enum Highlight {
+    None,
+    Match,
+    Current,
+}
+
+/// The shape of the torch's lit region, cycled at runtime. Recast from the
+/// cursor shapes a terminal exposes (block, beam, underline, hollow block).
+#[derive(Copy, Clone)]
+enum Profile {
+    /// A circle around the cursor — the original spotlight.
+    Radial,
+    /// A vertical column of light around the cursor's `x`.
+    Beam,
+    /// A horizontal band of light around the cursor's `y`.
+    Underline,
+    /// A hollow rectangular ring tracing the cursor's outline.
+    Box,
+}
+
+/// How the lit region fades into shadow past its edge.
+#[derive(Copy, Clone)]
+enum Falloff {
+    Linear,
+    Quadratic,
+    Smoothstep,
+}
+
+impl Profile {
+    /// Aspect-corrected distance from the lit region: `0.0` at the fully-lit
+    /// centre and growing to `1.0` at the edge set by `radius`. `(dx, dy)` is the
+    /// offset from the cursor.
+    fn distance(self, dx: f32, dy: f32, radius: f32) -> f32 {
+        match self {
+            Self::Radial => dx.hypot(dy) / radius,
+            Self::Beam => dx.abs() / radius,
+            Self::Underline => dy.abs() / radius,
+            // distance to the cursor's rectangular outline rather than its inside
+            Self::Box => (dx.abs().max(dy.abs()) - radius).abs() / radius,
+        }
+    }
+
+    /// The next profile in the cycle, wrapping back to [`Self::Radial`].
+    fn next(self) -> Self {
+        match self {
+            Self::Radial => Self::Beam,
+            Self::Beam => Self::Underline,
+            Self::Underline => Self::Box,
+            Self::Box => Self::Radial,
+        }
+    }
+}
+
+impl Falloff {
+    /// Map a normalized distance `d` (0 at the centre, 1 at the edge) to a shade
+    /// factor in `0.0..=1.0`, clamped beyond the edge.
+    fn apply(self, d: f32) -> f32 {
+        let d = d.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => d,
+            Self::Quadratic => d * d,
+            Self::Smoothstep => d * d * (3.0 - 2.0 * d),
+        }
+    }
+
+    /// The next falloff curve in the cycle, wrapping back to [`Self::Linear`].
+    fn next(self) -> Self {
+        match self {
+            Self::Linear => Self::Quadratic,
+            Self::Quadratic => Self::Smoothstep,
+            Self::Smoothstep => Self::Linear,
+        }
+    }
+}
+
+impl App {
+    /// Cells within a double-click that land within this window are treated as
+    /// one double-click and trigger semantic word selection.
+    const DOUBLE_CLICK: Duration = Duration::from_millis(400);
+
+    /// Tabs expand to the next multiple of this many cells during layout.
+    const TAB_STOP: usize = 8;
+
+    /// The number of terminal cells the glyph `c` occupies when it starts at
+    /// display column `col`: 0/1/2 for ordinary characters via `unicode-width`,
+    /// and a tab rounded up to the next [`Self::TAB_STOP`].
+    fn glyph_width(c: char, col: usize) -> usize {
+        if c == '\t' {
+            Self::TAB_STOP - (col % Self::TAB_STOP)
+        } else {
+            UnicodeWidthChar::width(c).unwrap_or(0)
+        }
+    }
+
+    /// Lay every logical line out into visual rows that each fit within `width`
+    /// display cells. A row is the half-open char slice `start..end` of its
+    /// logical `line`; breaks prefer the last whitespace before the limit and
+    /// fall back to a hard break for an unbroken run wider than the viewport.
+    fn wrap(&self, width: usize) -> Vec<Row> {
+        let mut rows = Vec::new();
+        for (idx, line) in self.lines.iter().enumerate() {
+            let chars: Vec<char> = line.chars().collect();
+            if chars.is_empty() {
+                rows.push(Row { line: idx, start: 0, end: 0 });
+                continue;
+            }
+
+            let mut seg_start = 0;
+            let mut col = 0;
+            let mut last_break: Option<usize> = None;
+            let mut i = 0;
+            while i < chars.len() {
+                let c = chars[i];
+                let w = Self::glyph_width(c, col);
+                if width > 0 && col + w > width && i > seg_start {
+                    // break at the last whitespace in the row, else hard-break here
+                    let brk = match last_break {
+                        Some(b) if b > seg_start => b,
+                        _ => i,
+                    };
+                    rows.push(Row { line: idx, start: seg_start, end: brk });
+                    seg_start = brk;
+                    i = brk;
+                    col = 0;
+                    last_break = None;
+                    continue;
+                }
+                col += w;
+                i += 1;
+                if c.is_whitespace() {
+                    last_break = Some(i);
+                }
+            }
+            rows.push(Row { line: idx, start: seg_start, end: chars.len() });
+        }
+        rows
+    }
+
+    /// Index of the first visual row on screen for `rows` within `rect`, matching
+    /// the `offset = rows.len() - pos` relation used everywhere the viewport is
+    /// scrolled. Shared by painting and hit-testing so they agree on what shows.
+    fn row_offset(&self, rows: &[Row], rect: Rect) -> usize {
+        let offset = rows.len().saturating_sub(self.pos);
+        offset
+            .checked_sub(rect.height().saturating_sub(1) as usize)
+            .unwrap_or(offset)
+    }
+
+    /// Walk the current viewport exactly as [`App::draw_ui`] does, recording
+    /// where every source glyph's lead cell landed so mouse hits can be mapped
+    /// back to `(line, column)`.
+    fn layout(&self) -> Vec<Placed> {
+        let rect = self.area;
+        let rows = self.wrap(rect.width() as usize);
+        let offset = self.row_offset(&rows, rect);
+
+        let mut placed = Vec::new();
+        let mut start = rect.left_top();
+        for row in rows.iter().skip(offset) {
+            if start.y >= rect.height() {
+                break;
+            }
+
+            let chars: Vec<char> = self.lines[row.line].chars().collect();
+            let mut col = 0;
+            for i in row.start..row.end {
+                let c = chars[i];
+                let w = Self::glyph_width(c, col);
+                col += w;
+                placed.push(Placed {
+                    pos: start,
+                    line: row.line,
+                    col: i,
+                });
+                start.x += 1;
+                for _ in 1..w {
+                    if start.x >= rect.width() {
+                        break;
+                    }
+                    start.x += 1;
+                }
+            }
+
+            start.x = rect.left();
+            start.y += 1;
+        }
+        placed
+    }
+
+    /// Map a screen cell back to the `(line, column)` it was painted from by
+    /// inverting the same offset/wrap walk [`App::draw_ui`] uses, so hits stay
+    /// correct while scrolled. A click past the end of a row clamps to the last
+    /// glyph at or before it.
+    fn hit(&self, pos: Pos2) -> Option<(usize, usize)> {
+        let placed = self.layout();
+        if let Some(p) = placed.iter().find(|p| p.pos == pos) {
+            return Some((p.line, p.col));
+        }
+        if let Some(p) = placed
+            .iter()
+            .filter(|p| p.pos.y == pos.y && p.pos.x <= pos.x)
+            .last()
+        {
+            return Some((p.line, p.col));
+        }
+        placed
+            .iter()
+            .filter(|p| p.pos.y <= pos.y)
+            .last()
+            .map(|p| (p.line, p.col))
+    }
+
+    fn begin_selection(&mut self, pos: Pos2) {
+        let double = matches!(
+            self.last_click,
+            Some((when, at)) if at == pos && when.elapsed() < Self::DOUBLE_CLICK
+        );
+        self.last_click = Some((Instant::now(), pos));
+
+        let Some(at) = self.hit(pos) else {
+            self.selection = None;
+            return;
+        };
+
+        if double {
+            self.select_word(at);
+        } else {
+            self.selection = Some(Selection {
+                anchor: at,
+                end: at,
+            });
+        }
+    }
+
+    fn drag_selection(&mut self, pos: Pos2) {
+        let Some(at) = self.hit(pos) else { return };
+        if let Some(selection) = self.selection.as_mut() {
+            selection.end = at;
+        }
+    }
+
+    /// Expand the selection to the word at `(line, col)`, stopping at the first
+    /// whitespace/punctuation transition on either side — mirroring a terminal's
+    /// semantic (double-click) selection.
+    fn select_word(&mut self, (line, col): (usize, usize)) {
+        let chars: Vec<char> = self.lines[line].chars().collect();
+        let is_word = |c: char| c.is_alphanumeric() || c == '_';
+        if !is_word(chars[col]) {
+            self.selection = Some(Selection {
+                anchor: (line, col),
+                end: (line, col),
+            });
+            return;
+        }
+
+        let mut lo = col;
+        while lo > 0 && is_word(chars[lo - 1]) {
+            lo -= 1;
+        }
+        let mut hi = col;
+        while hi + 1 < chars.len() && is_word(chars[hi + 1]) {
+            hi += 1;
+        }
+
+        self.selection = Some(Selection {
+            anchor: (line, lo),
+            end: (line, hi),
+        });
+    }
+
+    /// Whether the glyph at `(line, col)` falls inside the selection, in
+    /// row-major reading order.
+    fn in_selection(&self, line: usize, col: usize) -> bool {
+        let Some(selection) = self.selection.as_ref() else {
+            return false;
+        };
+
+        let (a, b) = if selection.anchor <= selection.end {
+            (selection.anchor, selection.end)
+        } else {
+            (selection.end, selection.anchor)
+        };
+        (line, col) >= a && (line, col) <= b
+    }
+
+    /// Gather the selected text straight out of [`App::lines`] by `(line, col)`
+    /// range — including anything scrolled off screen — joining lines with `\n`,
+    /// and hand it to the system clipboard. Both ends are inclusive, so a click
+    /// without a drag selects and copies the one glyph under it; there is no
+    /// zero-width selection. With no selection nothing is copied.
+    fn copy_selection(&mut self) {
+        let Some(selection) = self.selection.as_ref() else {
+            return;
+        };
+
+        let ((al, ac), (bl, bc)) = if selection.anchor <= selection.end {
+            (selection.anchor, selection.end)
+        } else {
+            (selection.end, selection.anchor)
+        };
+
+        let mut out = String::new();
+        for line in al..=bl {
+            if line != al {
+                out.push('\n');
+            }
+            let chars: Vec<char> = self.lines[line].chars().collect();
+            let from = if line == al { ac } else { 0 };
+            let to = if line == bl { bc } else { chars.len().saturating_sub(1) };
+            for col in from..=to {
+                if let Some(&c) = chars.get(col) {
+                    out.push(c);
+                }
+            }
+        }
+
+        if out.is_empty() {
+            return;
+        }
+
+        if let Ok(mut clipboard) = arboard::Clipboard::new() {
+            let _ = clipboard.set_text(out);
+        }
     }
 }
 
@@ -94,30 +710,47 @@ impl App {
     const FG: Rgb = Rgb::from_u32(0x000000);
     const BG: Rgb = Rgb::from_u32(0xF0E68C);
     const SHADOW: Rgb = Rgb::from_u32(0x333333);
+    const MATCH: Rgb = Rgb::from_u32(0xFF8C00);
+    const CURRENT: Rgb = Rgb::from_u32(0xFF4500);
+
+    /// Ambient dimming at the fully-lit centre. The original torch clamped its
+    /// radial distance to `1.5` before `lerp(0.0..=0.25, …)`, so even the cursor
+    /// cell sat at ~`0.375` shadow; the lit region floors here to keep that look.
+    const FLOOR: f32 = 0.375;
 
     fn draw_ui(&self, canvas: &mut Canvas) {
         canvas.fill(if self.enabled { Self::FG } else { Self::BG });
 
         let rect = canvas.area();
-        let offset = self.lines.len().saturating_sub(self.pos);
-        let offset = offset
-            .checked_sub(rect.height().saturating_sub(1) as usize)
-            .unwrap_or(offset);
+        let rows = self.wrap(rect.width() as usize);
+        let offset = self.row_offset(&rows, rect);
 
-        let width = rect.width();
         let mut start = rect.left_top();
-        for line in self.lines.iter().skip(offset) {
+        for row in rows.iter().skip(offset) {
             if start.y >= rect.height() {
                 break;
             }
 
-            for c in line.chars() {
-                if start.x >= width {
-                    start.x = rect.left();
-                    start.y += 1;
-                }
-                canvas.put(start, self.maybe_blend(start, c));
+            let chars: Vec<char> = self.lines[row.line].chars().collect();
+            let mut col = 0;
+            for i in row.start..row.end {
+                let c = chars[i];
+                let w = Self::glyph_width(c, col);
+                col += w;
+
+                let cell = self.maybe_blend(start, c);
+                let cell = self.with_highlight(cell, self.highlight(row.line, i));
+                canvas.put(start, self.with_selection(cell, row.line, i));
                 start.x += 1;
+
+                // a wide glyph owns the following cell(s); keep them lit but empty
+                for _ in 1..w {
+                    if start.x >= rect.width() {
+                        break;
+                    }
+                    canvas.put(start, self.maybe_blend(start, ' '));
+                    start.x += 1;
+                }
             }
 
             // fill in the rest of the line
@@ -142,19 +775,49 @@ impl App {
             return Cell::new(c).fg(Self::FG).bg(Self::BG);
         }
 
-        // length
-        let x = pos.x as f32 - self.cursor.x as f32;
-        let y = pos.y as f32 - self.cursor.y as f32;
+        // offset from the cursor, aspect-corrected for the cell geometry
+        // (probably wrong for not-my-setup)
+        let dx = (pos.x as f32 - self.cursor.x as f32) * 1.6;
+        let dy = (pos.y as f32 - self.cursor.y as f32) * 3.0;
 
-        // fix the aspect ratio (probably wrong for not-my-setup)
-        let x = x * 1.6;
-        let y = y * 3.0;
-
-        let distance = x.hypot(y).sqrt().max(1.5);
-        let blend = lerp(0.0..=0.25, distance);
+        // the selected shape decides how far this cell sits from the lit region,
+        // and the falloff curve shapes the fade from the centre floor to full
+        // shadow at the edge — matching the original's centre and far endpoints
+        let d = self.profile.distance(dx, dy, self.radius);
+        let blend = lerp(Self::FLOOR..=1.0, self.falloff.apply(d));
 
         Cell::new(c)
             .fg(Self::FG)
             .bg(Self::BG.blend_flat(Self::SHADOW, blend))
     }
+
+    fn cycle_profile(&mut self) {
+        self.profile = self.profile.next();
+    }
+
+    fn cycle_falloff(&mut self) {
+        self.falloff = self.falloff.next();
+    }
+
+    /// Give an already-lit cell a search highlight: its background is replaced
+    /// by a tint blended over [`Self::BG`], so torch shading does not show on
+    /// matched cells. The current match is tinted more strongly than the rest so
+    /// it stands out while navigating.
+    fn with_highlight(&self, cell: Cell, highlight: Highlight) -> Cell {
+        match highlight {
+            Highlight::None => cell,
+            Highlight::Match => cell.bg(Self::BG.blend_flat(Self::MATCH, 0.45)),
+            Highlight::Current => cell.bg(Self::BG.blend_flat(Self::CURRENT, 0.7)),
+        }
+    }
+
+    /// Invert a cell's colours when it falls inside the active selection, so the
+    /// selected run reads the way a terminal's highlighted text does.
+    fn with_selection(&self, cell: Cell, line: usize, col: usize) -> Cell {
+        if self.in_selection(line, col) {
+            cell.fg(Self::BG).bg(Self::FG)
+        } else {
+            cell
+        }
+    }
 }